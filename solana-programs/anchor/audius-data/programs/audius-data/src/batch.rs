@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_BATCH_SIZE;
+
+/// One entry in a batch registration payload: the numeric id
+/// (`track_id`/`playlist_id`) to stamp the new account with. Every record in
+/// a batch is owned by that batch's single authorized `CreateEntitiesBatch::user`
+/// — there is no per-record owner, since the instruction only ever checks one
+/// `authority` signature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BatchRecord {
+    pub id: u64,
+}
+
+/// Length, in bytes, of a single record's body (`id`, 8 bytes). Each encoded
+/// record is this body preceded by a one-byte tag giving the body's length.
+const RECORD_BODY_LEN: usize = 8;
+
+/// Decodes the compact, length-prefixed batch payload accepted by
+/// `create_tracks_batch`/`create_playlists_batch`: a leading count byte
+/// followed by that many length-prefixed records.
+///
+/// Returns [`BatchErrorCode::InvalidBatchPayload`] if the payload is truncated,
+/// declares more records than fit in the remaining bytes, or exceeds
+/// [`MAX_BATCH_SIZE`].
+pub fn parse_batch_payload(payload: &[u8]) -> Result<Vec<BatchRecord>> {
+    let (&count, mut rest) = payload.split_first().ok_or(BatchErrorCode::InvalidBatchPayload)?;
+
+    if count as usize > MAX_BATCH_SIZE {
+        return Err(BatchErrorCode::InvalidBatchPayload.into());
+    }
+
+    let mut records = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        let (&record_len, tail) = rest.split_first().ok_or(BatchErrorCode::InvalidBatchPayload)?;
+        if record_len as usize != RECORD_BODY_LEN || tail.len() < RECORD_BODY_LEN {
+            return Err(BatchErrorCode::InvalidBatchPayload.into());
+        }
+
+        let id = u64::from_le_bytes(tail[..RECORD_BODY_LEN].try_into().unwrap());
+        records.push(BatchRecord { id });
+
+        rest = &tail[RECORD_BODY_LEN..];
+    }
+
+    Ok(records)
+}
+
+#[error_code]
+pub enum BatchErrorCode {
+    #[msg("Batch payload is truncated, malformed, or exceeds the maximum batch size")]
+    InvalidBatchPayload,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(ids: &[u64]) -> Vec<u8> {
+        let mut payload = vec![ids.len() as u8];
+        for id in ids {
+            payload.push(RECORD_BODY_LEN as u8);
+            payload.extend_from_slice(&id.to_le_bytes());
+        }
+        payload
+    }
+
+    #[test]
+    fn rejects_empty_payload() {
+        assert!(parse_batch_payload(&[]).is_err());
+    }
+
+    #[test]
+    fn rejects_count_over_max_batch_size() {
+        let payload = vec![(MAX_BATCH_SIZE + 1) as u8];
+        assert!(parse_batch_payload(&payload).is_err());
+    }
+
+    #[test]
+    fn rejects_truncated_record() {
+        let mut payload = encode(&[1]);
+        payload.truncate(payload.len() - 1);
+        assert!(parse_batch_payload(&payload).is_err());
+    }
+
+    #[test]
+    fn rejects_record_with_wrong_length_prefix() {
+        let payload = vec![1u8, 3u8, 0u8, 0u8, 0u8];
+        assert!(parse_batch_payload(&payload).is_err());
+    }
+
+    #[test]
+    fn parses_well_formed_batch() {
+        let payload = encode(&[100, 200, 300]);
+        let records = parse_batch_payload(&payload).unwrap();
+        assert_eq!(
+            records,
+            vec![
+                BatchRecord { id: 100 },
+                BatchRecord { id: 200 },
+                BatchRecord { id: 300 },
+            ]
+        );
+    }
+}
@@ -12,19 +12,30 @@ pub const ADMIN_ACCOUNT_SIZE: usize = 8 + // anchor prefix
 /// Size of user account
 pub const USER_ACCOUNT_SIZE: usize = 8 + // anchor prefix
 20 + // eth_address: [u8; 20]
-32; // authority: Pubkey
+32 + // authority: Pubkey
+8; // seq: u64
 
 /// Size of track account
 pub const TRACK_ACCOUNT_SIZE: usize = 8 + // anchor prefix
 32 + // owner: Pubkey
-8; // track_id: u64
+8 + // track_id: u64
+8; // seq: u64
 
 /// Size of playlist account
 pub const PLAYLIST_ACCOUNT_SIZE: usize = 8 + // anchor prefix
 32 + // owner: Pubkey
-8; // playlist_id: u64
+8 + // playlist_id: u64
+8; // seq: u64
 
 /// Size of user authority delegation account
 pub const USER_AUTHORITY_DELEGATE_ACCOUNT_SIZE: usize = 8 + // anchor prefix
-32 + // delegate_authority: Pubkey 
-32; // user_storage_account: Pubkey
+32 + // delegate_authority: Pubkey
+32 + // user_storage_account: Pubkey
+1 + // permissions: u8 (bitmask, see delegate::Permission)
+8 + // expires_at_slot: u64
+8; // seq: u64
+
+/// Maximum number of records accepted by a single batch instruction, chosen
+/// to keep `create_tracks_batch`/`create_playlists_batch` comfortably under
+/// the per-transaction compute budget.
+pub const MAX_BATCH_SIZE: usize = 32;
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+/// Capability bits carried by a `UserAuthorityDelegate`'s `permissions`
+/// bitmask, generalizing the all-or-nothing delegate grant into per-capability
+/// control.
+///
+/// Only add a variant here once an instruction actually checks it — an unused
+/// bit is a grant nothing enforces.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Permission {
+    ManageTracks = 1 << 0,
+    ManagePlaylists = 1 << 1,
+}
+
+/// Checks that a delegate's `permissions` bitmask grants `required`, and that
+/// the delegate has not expired as of `current_slot`.
+///
+/// Every instruction that honors a `UserAuthorityDelegate` must route through
+/// this helper before trusting the delegate's signature in place of the
+/// user's own authority.
+pub fn assert_delegate_authorized(
+    permissions: u8,
+    expires_at_slot: u64,
+    current_slot: u64,
+    required: Permission,
+) -> Result<()> {
+    if current_slot >= expires_at_slot {
+        return Err(DelegateErrorCode::DelegateExpired.into());
+    }
+
+    if permissions & (required as u8) == 0 {
+        return Err(DelegateErrorCode::DelegateMissingPermission.into());
+    }
+
+    Ok(())
+}
+
+#[error_code]
+pub enum DelegateErrorCode {
+    #[msg("Delegate authority has expired")]
+    DelegateExpired,
+    #[msg("Delegate authority lacks the required permission")]
+    DelegateMissingPermission,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_expired_delegate() {
+        let permissions = Permission::ManageTracks as u8;
+        assert!(assert_delegate_authorized(permissions, 100, 100, Permission::ManageTracks).is_err());
+        assert!(assert_delegate_authorized(permissions, 100, 101, Permission::ManageTracks).is_err());
+    }
+
+    #[test]
+    fn rejects_missing_permission_bit() {
+        let permissions = Permission::ManagePlaylists as u8;
+        assert!(assert_delegate_authorized(permissions, 100, 0, Permission::ManageTracks).is_err());
+    }
+
+    #[test]
+    fn allows_unexpired_delegate_with_required_bit() {
+        let permissions = Permission::ManageTracks as u8 | Permission::ManagePlaylists as u8;
+        assert!(assert_delegate_authorized(permissions, 100, 99, Permission::ManageTracks).is_ok());
+        assert!(assert_delegate_authorized(permissions, 100, 99, Permission::ManagePlaylists).is_ok());
+    }
+}
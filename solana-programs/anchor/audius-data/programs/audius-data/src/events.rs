@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+/// Events emitted by mutating instruction handlers so off-chain indexers can
+/// reconstruct the content graph from the program log stream instead of
+/// polling and diffing account snapshots.
+///
+/// Every event carries `slot` (the slot the mutation landed in) and `seq`,
+/// a per-account monotonically increasing sequence number, so a consumer can
+/// detect dropped or out-of-order logs and fall back to an account refetch.
+#[event]
+pub struct UserCreated {
+    pub user_account: Pubkey,
+    pub authority: Pubkey,
+    pub slot: u64,
+    pub seq: u64,
+}
+
+#[event]
+pub struct TrackCreated {
+    pub track_account: Pubkey,
+    pub track_id: u64,
+    pub authority: Pubkey,
+    pub slot: u64,
+    pub seq: u64,
+}
+
+#[event]
+pub struct TrackUpdated {
+    pub track_account: Pubkey,
+    pub track_id: u64,
+    pub authority: Pubkey,
+    pub slot: u64,
+    pub seq: u64,
+}
+
+#[event]
+pub struct TrackDeleted {
+    pub track_account: Pubkey,
+    pub track_id: u64,
+    pub authority: Pubkey,
+    pub slot: u64,
+    pub seq: u64,
+}
+
+#[event]
+pub struct PlaylistUpdated {
+    pub playlist_account: Pubkey,
+    pub playlist_id: u64,
+    pub authority: Pubkey,
+    pub slot: u64,
+    pub seq: u64,
+}
+
+#[event]
+pub struct DelegateAdded {
+    pub user_storage_account: Pubkey,
+    pub delegate_authority: Pubkey,
+    pub authority: Pubkey,
+    pub slot: u64,
+    pub seq: u64,
+}
+
+#[event]
+pub struct DelegateRemoved {
+    pub user_storage_account: Pubkey,
+    pub delegate_authority: Pubkey,
+    pub authority: Pubkey,
+    pub slot: u64,
+    pub seq: u64,
+}
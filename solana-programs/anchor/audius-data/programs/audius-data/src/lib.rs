@@ -0,0 +1,441 @@
+use std::io::Cursor;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+
+pub mod batch;
+pub mod constants;
+pub mod delegate;
+pub mod events;
+pub mod secp256k1;
+pub mod state;
+
+use batch::{parse_batch_payload, BatchErrorCode};
+use constants::*;
+use delegate::{assert_delegate_authorized, Permission};
+use events::*;
+use secp256k1::{parse_secp256k1_instruction, Secp256k1ErrorCode};
+use state::*;
+
+declare_id!("AudUsDataProgram111111111111111111111111111");
+
+/// Errors raised directly by instruction handlers.
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Signer is not the account authority")]
+    Unauthorized,
+}
+
+#[program]
+pub mod audius_data {
+    use super::*;
+
+    /// Creates a `UserAccount` for the Ethereum address recovered from the
+    /// `Secp256k1Program` instruction immediately preceding this one in the
+    /// transaction, so a user is only created for an address whose owner
+    /// actually signed the request.
+    pub fn create_user(ctx: Context<CreateUser>, eth_address: [u8; 20]) -> Result<()> {
+        let current_index =
+            sysvar_instructions::load_current_index_checked(&ctx.accounts.sysvar_instructions)?;
+        require!(
+            current_index > 0,
+            Secp256k1ErrorCode::SignatureVerificationFailed
+        );
+        let secp_ix = sysvar_instructions::load_instruction_at_checked(
+            (current_index - 1) as usize,
+            &ctx.accounts.sysvar_instructions,
+        )?;
+        require_keys_eq!(
+            secp_ix.program_id,
+            anchor_lang::solana_program::secp256k1_program::ID,
+            Secp256k1ErrorCode::SignatureVerificationFailed
+        );
+        let (recovered_eth_address, _message) = parse_secp256k1_instruction(&secp_ix.data)?;
+        require!(
+            recovered_eth_address == eth_address,
+            Secp256k1ErrorCode::SignatureVerificationFailed
+        );
+
+        let user = &mut ctx.accounts.user;
+        user.eth_address = eth_address;
+        user.authority = ctx.accounts.authority.key();
+        user.seq = 1;
+
+        emit!(UserCreated {
+            user_account: user.key(),
+            authority: user.authority,
+            slot: Clock::get()?.slot,
+            seq: user.seq,
+        });
+
+        Ok(())
+    }
+
+    pub fn create_track(ctx: Context<CreateTrack>, track_id: u64) -> Result<()> {
+        let track = &mut ctx.accounts.track;
+        track.owner = ctx.accounts.user.key();
+        track.track_id = track_id;
+        track.seq = 1;
+
+        emit!(TrackCreated {
+            track_account: track.key(),
+            track_id,
+            authority: ctx.accounts.authority.key(),
+            slot: Clock::get()?.slot,
+            seq: track.seq,
+        });
+
+        Ok(())
+    }
+
+    pub fn update_track(ctx: Context<UpdateTrack>) -> Result<()> {
+        authorize_entity_write(
+            &ctx.accounts.user,
+            &ctx.accounts.authority,
+            ctx.accounts.user_authority_delegate.as_ref(),
+            Permission::ManageTracks,
+        )?;
+
+        let track = &mut ctx.accounts.track;
+        track.seq += 1;
+
+        emit!(TrackUpdated {
+            track_account: track.key(),
+            track_id: track.track_id,
+            authority: ctx.accounts.authority.key(),
+            slot: Clock::get()?.slot,
+            seq: track.seq,
+        });
+
+        Ok(())
+    }
+
+    pub fn delete_track(ctx: Context<DeleteTrack>) -> Result<()> {
+        authorize_entity_write(
+            &ctx.accounts.user,
+            &ctx.accounts.authority,
+            ctx.accounts.user_authority_delegate.as_ref(),
+            Permission::ManageTracks,
+        )?;
+
+        let track = &ctx.accounts.track;
+
+        emit!(TrackDeleted {
+            track_account: track.key(),
+            track_id: track.track_id,
+            authority: ctx.accounts.authority.key(),
+            slot: Clock::get()?.slot,
+            seq: track.seq + 1,
+        });
+
+        Ok(())
+    }
+
+    pub fn update_playlist(ctx: Context<UpdatePlaylist>) -> Result<()> {
+        authorize_entity_write(
+            &ctx.accounts.user,
+            &ctx.accounts.authority,
+            ctx.accounts.user_authority_delegate.as_ref(),
+            Permission::ManagePlaylists,
+        )?;
+
+        let playlist = &mut ctx.accounts.playlist;
+        playlist.seq += 1;
+
+        emit!(PlaylistUpdated {
+            playlist_account: playlist.key(),
+            playlist_id: playlist.playlist_id,
+            authority: ctx.accounts.authority.key(),
+            slot: Clock::get()?.slot,
+            seq: playlist.seq,
+        });
+
+        Ok(())
+    }
+
+    /// Grants `delegate_authority` a scoped, time-limited right to act on
+    /// `user`'s behalf. See [`delegate::Permission`] for the capability bits
+    /// and [`delegate::assert_delegate_authorized`] for how they, together
+    /// with `expires_at_slot`, are enforced at use time.
+    pub fn add_user_authority_delegate(
+        ctx: Context<AddUserAuthorityDelegate>,
+        permissions: u8,
+        expires_at_slot: u64,
+    ) -> Result<()> {
+        let delegate = &mut ctx.accounts.user_authority_delegate;
+        delegate.delegate_authority = ctx.accounts.delegate_authority.key();
+        delegate.user_storage_account = ctx.accounts.user.key();
+        delegate.permissions = permissions;
+        delegate.expires_at_slot = expires_at_slot;
+        delegate.seq = 1;
+
+        emit!(DelegateAdded {
+            user_storage_account: delegate.user_storage_account,
+            delegate_authority: delegate.delegate_authority,
+            authority: ctx.accounts.authority.key(),
+            slot: Clock::get()?.slot,
+            seq: delegate.seq,
+        });
+
+        Ok(())
+    }
+
+    pub fn remove_user_authority_delegate(
+        ctx: Context<RemoveUserAuthorityDelegate>,
+    ) -> Result<()> {
+        let delegate = &ctx.accounts.user_authority_delegate;
+
+        emit!(DelegateRemoved {
+            user_storage_account: delegate.user_storage_account,
+            delegate_authority: delegate.delegate_authority,
+            authority: ctx.accounts.authority.key(),
+            slot: Clock::get()?.slot,
+            seq: delegate.seq + 1,
+        });
+
+        Ok(())
+    }
+
+    /// Registers many tracks, all owned by `ctx.accounts.user`, in one
+    /// transaction. `payload` is decoded with [`batch::parse_batch_payload`];
+    /// `ctx.remaining_accounts` must list, in order, one uninitialized
+    /// `TrackAccount` per record.
+    pub fn create_tracks_batch(ctx: Context<CreateEntitiesBatch>, payload: Vec<u8>) -> Result<()> {
+        let records = parse_batch_payload(&payload)?;
+        require!(
+            records.len() <= ctx.remaining_accounts.len(),
+            BatchErrorCode::InvalidBatchPayload
+        );
+
+        let owner = ctx.accounts.user.key();
+        let slot = Clock::get()?.slot;
+        for (i, record) in records.iter().enumerate() {
+            let track_account_info = &ctx.remaining_accounts[i];
+
+            create_program_account(
+                track_account_info,
+                &ctx.accounts.payer,
+                &ctx.accounts.system_program,
+                TRACK_ACCOUNT_SIZE,
+            )?;
+            write_account_data(
+                track_account_info,
+                &TrackAccount {
+                    owner,
+                    track_id: record.id,
+                    seq: 1,
+                },
+            )?;
+
+            emit!(TrackCreated {
+                track_account: track_account_info.key(),
+                track_id: record.id,
+                authority: ctx.accounts.authority.key(),
+                slot,
+                seq: 1,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Registers many playlists, all owned by `ctx.accounts.user`, in one
+    /// transaction. See [`audius_data::create_tracks_batch`] for the shared
+    /// payload and `remaining_accounts` layout.
+    pub fn create_playlists_batch(
+        ctx: Context<CreateEntitiesBatch>,
+        payload: Vec<u8>,
+    ) -> Result<()> {
+        let records = parse_batch_payload(&payload)?;
+        require!(
+            records.len() <= ctx.remaining_accounts.len(),
+            BatchErrorCode::InvalidBatchPayload
+        );
+
+        let owner = ctx.accounts.user.key();
+        let slot = Clock::get()?.slot;
+        for (i, record) in records.iter().enumerate() {
+            let playlist_account_info = &ctx.remaining_accounts[i];
+
+            create_program_account(
+                playlist_account_info,
+                &ctx.accounts.payer,
+                &ctx.accounts.system_program,
+                PLAYLIST_ACCOUNT_SIZE,
+            )?;
+            write_account_data(
+                playlist_account_info,
+                &PlaylistAccount {
+                    owner,
+                    playlist_id: record.id,
+                    seq: 1,
+                },
+            )?;
+
+            emit!(PlaylistUpdated {
+                playlist_account: playlist_account_info.key(),
+                playlist_id: record.id,
+                authority: ctx.accounts.authority.key(),
+                slot,
+                seq: 1,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Verifies that `authority` is allowed to mutate `user`'s entity: either it
+/// is `user`'s own authority, or it is a `UserAuthorityDelegate` for `user`
+/// that holds `required` and has not expired.
+fn authorize_entity_write<'info>(
+    user: &Account<'info, UserAccount>,
+    authority: &Signer<'info>,
+    delegate: Option<&Account<'info, UserAuthorityDelegate>>,
+    required: Permission,
+) -> Result<()> {
+    if authority.key() == user.authority {
+        return Ok(());
+    }
+
+    let delegate = delegate.ok_or(ErrorCode::Unauthorized)?;
+    require_keys_eq!(
+        delegate.delegate_authority,
+        authority.key(),
+        ErrorCode::Unauthorized
+    );
+    require_keys_eq!(
+        delegate.user_storage_account,
+        user.key(),
+        ErrorCode::Unauthorized
+    );
+
+    assert_delegate_authorized(
+        delegate.permissions,
+        delegate.expires_at_slot,
+        Clock::get()?.slot,
+        required,
+    )
+}
+
+fn create_program_account<'info>(
+    account_info: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    space: usize,
+) -> Result<()> {
+    let lamports = Rent::get()?.minimum_balance(space);
+    anchor_lang::system_program::create_account(
+        CpiContext::new(
+            system_program.to_account_info(),
+            anchor_lang::system_program::CreateAccount {
+                from: payer.to_account_info(),
+                to: account_info.clone(),
+            },
+        ),
+        lamports,
+        space as u64,
+        &crate::ID,
+    )
+}
+
+fn write_account_data<'info, T: AccountSerialize>(
+    account_info: &AccountInfo<'info>,
+    data: &T,
+) -> Result<()> {
+    let mut account_data = account_info.try_borrow_mut_data()?;
+    let mut writer = Cursor::new(&mut account_data[..]);
+    data.try_serialize(&mut writer)?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateUser<'info> {
+    #[account(init, payer = payer, space = USER_ACCOUNT_SIZE)]
+    pub user: Account<'info, UserAccount>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    /// CHECK: only read via `load_instruction_at_checked`, which validates it
+    /// is the sysvar instructions account.
+    pub sysvar_instructions: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CreateTrack<'info> {
+    #[account(init, payer = payer, space = TRACK_ACCOUNT_SIZE)]
+    pub track: Account<'info, TrackAccount>,
+    pub user: Account<'info, UserAccount>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(constraint = authority.key() == user.authority @ ErrorCode::Unauthorized)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateTrack<'info> {
+    #[account(mut, constraint = track.owner == user.key() @ ErrorCode::Unauthorized)]
+    pub track: Account<'info, TrackAccount>,
+    pub user: Account<'info, UserAccount>,
+    pub authority: Signer<'info>,
+    pub user_authority_delegate: Option<Account<'info, UserAuthorityDelegate>>,
+}
+
+#[derive(Accounts)]
+pub struct DeleteTrack<'info> {
+    #[account(mut, close = authority, constraint = track.owner == user.key() @ ErrorCode::Unauthorized)]
+    pub track: Account<'info, TrackAccount>,
+    pub user: Account<'info, UserAccount>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub user_authority_delegate: Option<Account<'info, UserAuthorityDelegate>>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePlaylist<'info> {
+    #[account(mut, constraint = playlist.owner == user.key() @ ErrorCode::Unauthorized)]
+    pub playlist: Account<'info, PlaylistAccount>,
+    pub user: Account<'info, UserAccount>,
+    pub authority: Signer<'info>,
+    pub user_authority_delegate: Option<Account<'info, UserAuthorityDelegate>>,
+}
+
+#[derive(Accounts)]
+pub struct AddUserAuthorityDelegate<'info> {
+    #[account(init, payer = payer, space = USER_AUTHORITY_DELEGATE_ACCOUNT_SIZE)]
+    pub user_authority_delegate: Account<'info, UserAuthorityDelegate>,
+    pub user: Account<'info, UserAccount>,
+    /// CHECK: recorded as the delegate's identity; no data is read from it.
+    pub delegate_authority: AccountInfo<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(constraint = authority.key() == user.authority @ ErrorCode::Unauthorized)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveUserAuthorityDelegate<'info> {
+    #[account(
+        mut,
+        close = authority,
+        constraint = user_authority_delegate.user_storage_account == user.key() @ ErrorCode::Unauthorized
+    )]
+    pub user_authority_delegate: Account<'info, UserAuthorityDelegate>,
+    #[account(constraint = authority.key() == user.authority @ ErrorCode::Unauthorized)]
+    pub user: Account<'info, UserAccount>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CreateEntitiesBatch<'info> {
+    pub user: Account<'info, UserAccount>,
+    #[account(constraint = authority.key() == user.authority @ ErrorCode::Unauthorized)]
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,171 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{ETH_ADDRESS_OFFSET, MESSAGE_OFFSET};
+
+/// Size in bytes of a single `SecpSignatureOffsets` entry as serialized by
+/// the native `Secp256k1Program`.
+///
+/// See https://docs.solana.com/developing/runtime-facilities/programs#secp256k1-program
+const SECP256K1_SIGNATURE_OFFSETS_SERIALIZED_SIZE: usize = 11;
+
+/// One `SecpSignatureOffsets` entry: the byte offsets (relative to the
+/// referenced instruction's data) of the signature, recovered Ethereum
+/// address, and signed message that `Secp256k1Program` wrote.
+struct SecpSignatureOffsets {
+    signature_offset: u16,
+    signature_instruction_index: u8,
+    eth_address_offset: u16,
+    eth_address_instruction_index: u8,
+    message_data_offset: u16,
+    message_data_size: u16,
+    message_instruction_index: u8,
+}
+
+impl SecpSignatureOffsets {
+    fn parse(bytes: &[u8]) -> Self {
+        Self {
+            signature_offset: u16::from_le_bytes([bytes[0], bytes[1]]),
+            signature_instruction_index: bytes[2],
+            eth_address_offset: u16::from_le_bytes([bytes[3], bytes[4]]),
+            eth_address_instruction_index: bytes[5],
+            message_data_offset: u16::from_le_bytes([bytes[6], bytes[7]]),
+            message_data_size: u16::from_le_bytes([bytes[8], bytes[9]]),
+            message_instruction_index: bytes[10],
+        }
+    }
+}
+
+/// Parses and bounds-checks the instruction data produced by the preceding
+/// `Secp256k1Program` instruction, returning the recovered Ethereum address
+/// and the signed message rather than letting callers index into the raw
+/// bytes directly.
+///
+/// `ix_data` must be at least `MESSAGE_OFFSET` bytes long, declare exactly
+/// one signature, and that signature's offsets must agree with the fixed
+/// `ETH_ADDRESS_OFFSET`/`MESSAGE_OFFSET` layout this program expects and
+/// stay within the instruction's own bounds. Anything shorter or
+/// inconsistent is rejected with [`Secp256k1ErrorCode::SignatureVerificationFailed`]
+/// instead of panicking or reading garbage.
+pub fn parse_secp256k1_instruction(ix_data: &[u8]) -> Result<([u8; 20], &[u8])> {
+    if ix_data.len() < MESSAGE_OFFSET {
+        return Err(Secp256k1ErrorCode::SignatureVerificationFailed.into());
+    }
+
+    let num_signatures = ix_data[0];
+    if num_signatures != 1 {
+        return Err(Secp256k1ErrorCode::SignatureVerificationFailed.into());
+    }
+
+    let header_end = 1 + SECP256K1_SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+    if header_end > ix_data.len() || header_end > MESSAGE_OFFSET {
+        return Err(Secp256k1ErrorCode::SignatureVerificationFailed.into());
+    }
+
+    let offsets = SecpSignatureOffsets::parse(&ix_data[1..header_end]);
+
+    // All three fields must refer back to this same instruction (0xff is
+    // Solana's "current instruction" sentinel) and must agree with the fixed
+    // offsets this program reads the address/message from.
+    const CURRENT_INSTRUCTION: u8 = u8::MAX;
+    if offsets.signature_instruction_index != CURRENT_INSTRUCTION
+        || offsets.eth_address_instruction_index != CURRENT_INSTRUCTION
+        || offsets.message_instruction_index != CURRENT_INSTRUCTION
+    {
+        return Err(Secp256k1ErrorCode::SignatureVerificationFailed.into());
+    }
+
+    if offsets.eth_address_offset as usize != ETH_ADDRESS_OFFSET
+        || offsets.message_data_offset as usize != MESSAGE_OFFSET
+    {
+        return Err(Secp256k1ErrorCode::SignatureVerificationFailed.into());
+    }
+
+    let eth_address_end = ETH_ADDRESS_OFFSET + 20;
+    if eth_address_end > MESSAGE_OFFSET || eth_address_end > ix_data.len() {
+        return Err(Secp256k1ErrorCode::SignatureVerificationFailed.into());
+    }
+
+    let signature_end = offsets.signature_offset as usize + 65;
+    if signature_end > ix_data.len() {
+        return Err(Secp256k1ErrorCode::SignatureVerificationFailed.into());
+    }
+
+    let message_end = MESSAGE_OFFSET
+        .checked_add(offsets.message_data_size as usize)
+        .ok_or(Secp256k1ErrorCode::SignatureVerificationFailed)?;
+    if message_end > ix_data.len() {
+        return Err(Secp256k1ErrorCode::SignatureVerificationFailed.into());
+    }
+
+    let mut eth_address = [0u8; 20];
+    eth_address.copy_from_slice(&ix_data[ETH_ADDRESS_OFFSET..eth_address_end]);
+
+    let message = &ix_data[MESSAGE_OFFSET..message_end];
+
+    Ok((eth_address, message))
+}
+
+#[error_code]
+pub enum Secp256k1ErrorCode {
+    #[msg("Secp256k1 instruction is missing, malformed, or recovers a different address")]
+    SignatureVerificationFailed,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn valid_instruction(message: &[u8]) -> Vec<u8> {
+        const CURRENT_INSTRUCTION: u8 = u8::MAX;
+
+        let mut data = vec![1u8]; // num_signatures
+        data.extend_from_slice(&0u16.to_le_bytes()); // signature_offset
+        data.push(CURRENT_INSTRUCTION); // signature_instruction_index
+        data.extend_from_slice(&(ETH_ADDRESS_OFFSET as u16).to_le_bytes());
+        data.push(CURRENT_INSTRUCTION); // eth_address_instruction_index
+        data.extend_from_slice(&(MESSAGE_OFFSET as u16).to_le_bytes());
+        data.extend_from_slice(&(message.len() as u16).to_le_bytes());
+        data.push(CURRENT_INSTRUCTION); // message_instruction_index
+
+        data.resize(MESSAGE_OFFSET, 0);
+        data[ETH_ADDRESS_OFFSET..ETH_ADDRESS_OFFSET + 20].copy_from_slice(&[7u8; 20]);
+        data.extend_from_slice(message);
+        data
+    }
+
+    #[test]
+    fn rejects_short_instruction_data() {
+        let ix_data = vec![1u8; MESSAGE_OFFSET - 1];
+        assert!(parse_secp256k1_instruction(&ix_data).is_err());
+    }
+
+    #[test]
+    fn rejects_zero_signatures() {
+        let mut ix_data = vec![0u8];
+        ix_data.resize(MESSAGE_OFFSET + 1, 0);
+        assert!(parse_secp256k1_instruction(&ix_data).is_err());
+    }
+
+    #[test]
+    fn rejects_truncated_offsets_header() {
+        let mut ix_data = vec![1u8];
+        ix_data.extend_from_slice(&[0u8; 3]); // far short of the 11-byte header
+        assert!(parse_secp256k1_instruction(&ix_data).is_err());
+    }
+
+    #[test]
+    fn rejects_offsets_pointing_elsewhere() {
+        let mut ix_data = valid_instruction(b"hello");
+        // Corrupt eth_address_offset so it no longer matches ETH_ADDRESS_OFFSET.
+        ix_data[4..6].copy_from_slice(&0u16.to_le_bytes());
+        assert!(parse_secp256k1_instruction(&ix_data).is_err());
+    }
+
+    #[test]
+    fn extracts_address_and_message_from_well_formed_instruction() {
+        let ix_data = valid_instruction(b"hello world");
+        let (recovered, message) = parse_secp256k1_instruction(&ix_data).unwrap();
+        assert_eq!(recovered, [7u8; 20]);
+        assert_eq!(message, b"hello world");
+    }
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+/// Global program admin. `is_write_enabled` and `verifier` are stored here
+/// only: no instruction in this program reads this account, so writes are not
+/// gated on `is_write_enabled` and no signature by `verifier` is checked.
+#[account]
+pub struct AdminAccount {
+    pub authority: Pubkey,
+    pub verifier: Pubkey,
+    pub track_id: u64,
+    pub playlist_id: u64,
+    pub is_write_enabled: bool,
+}
+
+/// One Audius user, keyed by their Ethereum address and controlled by a
+/// Solana authority. `seq` is bumped on every mutation so indexers consuming
+/// the corresponding events can detect gaps.
+#[account]
+pub struct UserAccount {
+    pub eth_address: [u8; 20],
+    pub authority: Pubkey,
+    pub seq: u64,
+}
+
+#[account]
+pub struct TrackAccount {
+    pub owner: Pubkey,
+    pub track_id: u64,
+    pub seq: u64,
+}
+
+#[account]
+pub struct PlaylistAccount {
+    pub owner: Pubkey,
+    pub playlist_id: u64,
+    pub seq: u64,
+}
+
+/// A scoped, time-limited grant of a user's authority to another keypair.
+///
+/// `permissions` is a [`crate::delegate::Permission`] bitmask and
+/// `expires_at_slot` bounds how long the grant is honored; both are enforced
+/// by [`crate::delegate::assert_delegate_authorized`] wherever a delegate
+/// signature is accepted in place of the user's own authority.
+#[account]
+pub struct UserAuthorityDelegate {
+    pub delegate_authority: Pubkey,
+    pub user_storage_account: Pubkey,
+    pub permissions: u8,
+    pub expires_at_slot: u64,
+    pub seq: u64,
+}